@@ -0,0 +1,23 @@
+use std::process::Command;
+
+// Exposes the git commit as GIT_COMMIT_HASH for the startup banner. A value already
+// set in the environment wins, for builds without a .git directory (e.g. Docker).
+fn main() {
+    println!("cargo:rerun-if-env-changed=GIT_COMMIT_HASH");
+    println!("cargo:rerun-if-changed=.git/HEAD");
+    println!("cargo:rerun-if-changed=.git/refs");
+
+    let commit = std::env::var("GIT_COMMIT_HASH").ok().or_else(|| {
+        Command::new("git")
+            .args(["rev-parse", "--short", "HEAD"])
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .and_then(|output| String::from_utf8(output.stdout).ok())
+            .map(|commit| commit.trim().to_string())
+    });
+    println!(
+        "cargo:rustc-env=GIT_COMMIT_HASH={}",
+        commit.unwrap_or_else(|| "unknown".to_string())
+    );
+}